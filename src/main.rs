@@ -7,20 +7,61 @@
 // - Composition + Default trait methods for "INHERITANCE"-like behavior
 // =============================================================================
 
+use std::collections::HashMap;
+use std::ops::{Deref, Index, Range};
+
 // =============================================================================
 // 1. ABSTRACTION - Define behavior without implementation details
 // =============================================================================
 // Traits define WHAT an object can do, not HOW it does it
 
-trait Animal {
+pub trait Animal {
     // Abstract method - each type MUST implement
     fn speak(&self) -> String;
     fn name(&self) -> &str;
-    
+
+    // Associated types - statically relate each implementor to its own
+    // sound and diet, instead of adding a new trait per animal category.
+    // Caveat: every dynamic-dispatch use site (`DynAnimal` below) has to
+    // pin these to one concrete pair to stay object-safe, so generic
+    // code (`feed_plan`) gets real per-type association, but `dyn Animal`
+    // callers only ever see the pinned types. All current implementors
+    // happen to agree on `Sound = &'static str` and `Diet = Diet`; a
+    // type that needed a genuinely different `Sound`/`Diet` could still
+    // implement `Animal` but couldn't be stored as a `DynAnimal`.
+    type Sound: std::fmt::Display;
+    type Diet;
+
+    fn sound(&self) -> Self::Sound;
+    fn preferred_food(&self) -> Self::Diet;
+
+    // Associated constructor - lets generic/factory code build an instance
+    // from just a name, without knowing the concrete type's full constructor
+    fn new(name: &str) -> Self
+    where
+        Self: Sized;
+
+    // Encapsulated hunger/energy state - each type owns its own field,
+    // exposed through this accessor pair (mirrors Sheep/shear-style
+    // mutable-state modeling instead of fixed, stateless strings)
+    fn hunger(&self) -> u8;
+    fn hunger_mut(&mut self) -> &mut u8;
+
     // Default implementation - "inherited" by all implementors
     fn describe(&self) -> String {
         format!("{} says: {}", self.name(), self.speak())
     }
+
+    // Default implementation - decrements hunger, shared by every implementor
+    fn feed(&mut self) {
+        let hunger = self.hunger_mut();
+        *hunger = hunger.saturating_sub(20);
+    }
+
+    // Default implementation, computed purely through `hunger()`
+    fn is_hungry(&self) -> bool {
+        self.hunger() > 50
+    }
 }
 
 // Secondary trait for additional behavior
@@ -30,6 +71,24 @@ trait Swimmer {
     }
 }
 
+// Concrete diet categories every `Animal` implementor maps to via its
+// associated `Diet` type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Diet {
+    Carnivore,
+    Herbivore,
+    Omnivore,
+}
+
+// `Animal` carries associated types, so a trait object must bind them to
+// stay object-safe. Every implementor in this crate happens to share the
+// same concrete Sound/Diet, so one alias covers every dynamic-dispatch
+// site - but that also means `Zoo`/`AnimalFactory`/etc. can only ever
+// hold animals whose Sound/Diet match this exact pinned pair. A type
+// with a different `Sound`/`Diet` would need its own `dyn Animal<...>`
+// alias and couldn't mix into the same collection as this one.
+pub type DynAnimal = dyn Animal<Sound = &'static str, Diet = Diet>;
+
 // =============================================================================
 // 2. ENCAPSULATION - Hide internal state, expose controlled interface
 // =============================================================================
@@ -38,6 +97,7 @@ pub struct Dog {
     name: String,           // Private - cannot access directly from outside
     age: u8,                // Private
     breed: String,          // Private
+    hunger: u8,             // Private - 0 (full) to 100 (starving)
 }
 
 impl Dog {
@@ -47,9 +107,10 @@ impl Dog {
             name: name.to_string(),
             age,
             breed: breed.to_string(),
+            hunger: 60,
         }
     }
-    
+
     // Public getter - controlled read access
     pub fn get_age(&self) -> u8 {
         self.age
@@ -70,13 +131,44 @@ impl Dog {
 
 // Implement the Animal trait for Dog
 impl Animal for Dog {
+    type Sound = &'static str;
+    type Diet = Diet;
+
     fn speak(&self) -> String {
-        format!("Woof! I'm a {}", self.format_breed())
+        if self.is_hungry() {
+            format!("{}... I'm a hungry {}", self.sound(), self.format_breed())
+        } else {
+            format!("{}! I'm a {}", self.sound(), self.format_breed())
+        }
     }
-    
+
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn sound(&self) -> Self::Sound {
+        if self.is_hungry() {
+            "Whimper"
+        } else {
+            "Woof"
+        }
+    }
+
+    fn preferred_food(&self) -> Self::Diet {
+        Diet::Omnivore
+    }
+
+    fn new(name: &str) -> Self {
+        Self::new(name, 0, "Unknown")
+    }
+
+    fn hunger(&self) -> u8 {
+        self.hunger
+    }
+
+    fn hunger_mut(&mut self) -> &mut u8 {
+        &mut self.hunger
+    }
 }
 
 // =============================================================================
@@ -86,6 +178,7 @@ impl Animal for Dog {
 pub struct Cat {
     name: String,
     indoor: bool,
+    hunger: u8,
 }
 
 impl Cat {
@@ -93,45 +186,99 @@ impl Cat {
         Self {
             name: name.to_string(),
             indoor,
+            hunger: 60,
         }
     }
 }
 
 // Same trait, different implementation
 impl Animal for Cat {
+    type Sound = &'static str;
+    type Diet = Diet;
+
     fn speak(&self) -> String {
-        if self.indoor {
-            String::from("Meow~ (comfortable purr)")
-        } else {
-            String::from("MEOW! (street cat attitude)")
+        match (self.indoor, self.is_hungry()) {
+            (true, true) => format!("{}! (demanding dinner)", self.sound()),
+            (true, false) => format!("{}~ (comfortable purr)", self.sound()),
+            (false, true) => format!("{}! (hungry street cat attitude)", self.sound().to_uppercase()),
+            (false, false) => format!("{}! (street cat attitude)", self.sound().to_uppercase()),
         }
     }
-    
+
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn sound(&self) -> Self::Sound {
+        "Meow"
+    }
+
+    fn preferred_food(&self) -> Self::Diet {
+        Diet::Carnivore
+    }
+
+    fn new(name: &str) -> Self {
+        Self::new(name, true)
+    }
+
+    fn hunger(&self) -> u8 {
+        self.hunger
+    }
+
+    fn hunger_mut(&mut self) -> &mut u8 {
+        &mut self.hunger
+    }
 }
 
 pub struct Duck {
     name: String,
+    hunger: u8,
 }
 
 impl Duck {
     pub fn new(name: &str) -> Self {
         Self {
             name: name.to_string(),
+            hunger: 60,
         }
     }
 }
 
 impl Animal for Duck {
+    type Sound = &'static str;
+    type Diet = Diet;
+
     fn speak(&self) -> String {
-        String::from("Quack quack!")
+        if self.is_hungry() {
+            format!("{} {}! (hungry)", self.sound().to_uppercase(), self.sound().to_uppercase())
+        } else {
+            format!("{} {}!", self.sound(), self.sound().to_lowercase())
+        }
     }
-    
+
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn sound(&self) -> Self::Sound {
+        "Quack"
+    }
+
+    fn preferred_food(&self) -> Self::Diet {
+        Diet::Omnivore
+    }
+
+    fn new(name: &str) -> Self {
+        Self::new(name)
+    }
+
+    fn hunger(&self) -> u8 {
+        self.hunger
+    }
+
+    fn hunger_mut(&mut self) -> &mut u8 {
+        &mut self.hunger
+    }
 }
 
 // Duck can also swim - multiple trait implementation
@@ -144,7 +291,9 @@ impl Swimmer for Duck {
 // =============================================================================
 // 4. "INHERITANCE" via Composition + Trait Defaults
 // =============================================================================
-// Rust favors composition over inheritance
+// Rust favors composition over inheritance. `HasBase` + a blanket `Walk`
+// impl turn "derived" structs that expose their base into free inheritors
+// of base behavior, instead of each one hand-writing delegation.
 
 // Base "class" as a struct
 struct AnimalBase {
@@ -159,16 +308,46 @@ impl AnimalBase {
             legs,
         }
     }
-    
+
     fn walk(&self) -> String {
         format!("{} walks on {} legs", self.name, self.legs)
     }
+
+    fn rename(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+}
+
+// Exposes a type's embedded `AnimalBase` so base behavior can be "inherited"
+// via a blanket impl instead of hand-written delegation per type.
+trait HasBase {
+    fn base(&self) -> &AnimalBase;
+    fn base_mut(&mut self) -> &mut AnimalBase;
+
+    // Default "inherited" behavior that needs mutable base access
+    fn rename(&mut self, name: &str) {
+        self.base_mut().rename(name);
+    }
+}
+
+// Anything that exposes its base gets `walk()` for free - no per-type
+// delegation boilerplate. `rename` is "inherited" the same way through a
+// `HasBase` default method, and further `AnimalBase` methods can follow.
+trait Walk {
+    fn walk(&self) -> String;
+}
+
+impl<T: HasBase> Walk for T {
+    fn walk(&self) -> String {
+        self.base().walk()
+    }
 }
 
 // "Derived class" using composition
 struct Horse {
     base: AnimalBase,       // Embed the base struct
     speed_mph: u32,
+    hunger: u8,
 }
 
 impl Horse {
@@ -176,28 +355,210 @@ impl Horse {
         Self {
             base: AnimalBase::new(name, 4),  // Horses have 4 legs
             speed_mph,
+            hunger: 60,
         }
     }
-    
-    // Delegate to base
-    fn walk(&self) -> String {
-        self.base.walk()
-    }
-    
+
     // Extended behavior
     fn gallop(&self) -> String {
         format!("{} gallops at {} mph!", self.base.name, self.speed_mph)
     }
 }
 
+impl HasBase for Horse {
+    fn base(&self) -> &AnimalBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut AnimalBase {
+        &mut self.base
+    }
+}
+
 impl Animal for Horse {
+    type Sound = &'static str;
+    type Diet = Diet;
+
     fn speak(&self) -> String {
-        String::from("Neigh!")
+        format!("{}!", self.sound())
     }
-    
+
     fn name(&self) -> &str {
         &self.base.name
     }
+
+    fn sound(&self) -> Self::Sound {
+        "Neigh"
+    }
+
+    fn preferred_food(&self) -> Self::Diet {
+        Diet::Herbivore
+    }
+
+    fn new(name: &str) -> Self {
+        Self::new(name, 30)
+    }
+
+    fn hunger(&self) -> u8 {
+        self.hunger
+    }
+
+    fn hunger_mut(&mut self) -> &mut u8 {
+        &mut self.hunger
+    }
+}
+
+// =============================================================================
+// 5. COLLECTIONS - Make `Zoo` behave like a built-in container
+// =============================================================================
+// Implementing the standard "protocol" traits (Index, IntoIterator,
+// FromIterator, Deref) lets `Zoo` support indexing, slicing, iteration,
+// and `.collect()` the same way a `Vec` does, instead of callers juggling
+// a bare `Vec<Box<DynAnimal>>` by hand.
+
+pub struct Zoo {
+    animals: Vec<Box<DynAnimal>>,
+}
+
+impl Zoo {
+    pub fn new() -> Self {
+        Self {
+            animals: Vec::new(),
+        }
+    }
+
+    pub fn add(&mut self, animal: Box<DynAnimal>) {
+        self.animals.push(animal);
+    }
+
+    // Every animal's `describe()` joined into one string
+    pub fn chorus(&self) -> String {
+        self.animals
+            .iter()
+            .map(|a| a.describe())
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+}
+
+impl Default for Zoo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Positional access: `zoo[0]`
+impl Index<usize> for Zoo {
+    type Output = Box<DynAnimal>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.animals[index]
+    }
+}
+
+// Slice access: `&zoo[0..2]`
+impl Index<Range<usize>> for Zoo {
+    type Output = [Box<DynAnimal>];
+
+    fn index(&self, range: Range<usize>) -> &Self::Output {
+        &self.animals[range]
+    }
+}
+
+// Deref to a slice gives `.len()`, `.iter()`, `.is_empty()` for free
+impl Deref for Zoo {
+    type Target = [Box<DynAnimal>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.animals
+    }
+}
+
+impl IntoIterator for Zoo {
+    type Item = Box<DynAnimal>;
+    type IntoIter = std::vec::IntoIter<Box<DynAnimal>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.animals.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Zoo {
+    type Item = &'a Box<DynAnimal>;
+    type IntoIter = std::slice::Iter<'a, Box<DynAnimal>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.animals.iter()
+    }
+}
+
+impl FromIterator<Box<DynAnimal>> for Zoo {
+    fn from_iter<I: IntoIterator<Item = Box<DynAnimal>>>(iter: I) -> Self {
+        Self {
+            animals: iter.into_iter().collect(),
+        }
+    }
+}
+
+// =============================================================================
+// 6. FACTORY/REGISTRY - Spawn animals by species name at runtime
+// =============================================================================
+// A registry of constructor closures lets callers build animals from a
+// species key (e.g. from configuration or user input) instead of a
+// hard-coded `match`, demonstrating data-driven polymorphism on top of
+// the existing `Animal` trait.
+
+// Named constructor type, mirroring the `hatch_a_bird(species) -> Box<dyn
+// Bird>` trait-object factory pattern this registry is modeled on
+type AnimalCtor = Box<dyn Fn(&str) -> Box<DynAnimal>>;
+
+pub struct AnimalFactory {
+    constructors: HashMap<String, AnimalCtor>,
+}
+
+impl AnimalFactory {
+    pub fn new() -> Self {
+        Self {
+            constructors: HashMap::new(),
+        }
+    }
+
+    pub fn register<F>(&mut self, key: &str, ctor: F)
+    where
+        F: Fn(&str) -> Box<DynAnimal> + 'static,
+    {
+        self.constructors.insert(key.to_string(), Box::new(ctor));
+    }
+
+    pub fn spawn(&self, key: &str, name: &str) -> Option<Box<DynAnimal>> {
+        self.constructors.get(key).map(|ctor| ctor(name))
+    }
+
+    // Preload the "dog", "cat" and "duck" species; `Horse` is not registered
+    pub fn with_defaults() -> Self {
+        let mut factory = Self::new();
+        factory.register("dog", |name| Box::new(Dog::new(name, 0, "Mixed")));
+        factory.register("cat", |name| Box::new(Cat::new(name, true)));
+        factory.register("duck", |name| Box::new(Duck::new(name)));
+        factory
+    }
+}
+
+impl Default for AnimalFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// =============================================================================
+// 7. ASSOCIATED TYPES - Generic routines over each Animal's own Diet
+// =============================================================================
+// Because `Diet` is an associated type rather than a fixed `Animal`
+// method signature, this function works for any concrete `Animal` type
+// and returns that type's own `Diet`, resolved entirely at compile time.
+
+fn feed_plan<T: Animal>(a: &T) -> T::Diet {
+    a.preferred_food()
 }
 
 // =============================================================================
@@ -205,7 +566,7 @@ impl Animal for Horse {
 // =============================================================================
 
 // Function accepting any Animal - POLYMORPHISM via trait objects
-fn introduce_animal(animal: &dyn Animal) {
+fn introduce_animal(animal: &DynAnimal) {
     println!("  {}", animal.describe());
 }
 
@@ -224,7 +585,7 @@ fn main() {
     let cat = Cat::new("Whiskers", true);
     let street_cat = Cat::new("Shadow", false);
     let duck = Duck::new("Donald");
-    let horse = Horse::new("Spirit", 35);
+    let mut horse = Horse::new("Spirit", 35);
     
     // ---------------------------------------------------------------------
     println!("\n1. ENCAPSULATION DEMO:");
@@ -250,7 +611,7 @@ fn main() {
     println!("-".repeat(40));
     
     // Using trait objects (dynamic dispatch)
-    let animals: Vec<&dyn Animal> = vec![&dog, &cat, &street_cat, &duck, &horse];
+    let animals: Vec<&DynAnimal> = vec![&dog, &cat, &street_cat, &duck, &horse];
     
     for animal in &animals {
         introduce_animal(*animal);
@@ -268,9 +629,12 @@ fn main() {
     println!("\n5. COMPOSITION ('Inheritance' Rust-style):");
     println!("-".repeat(40));
     
-    println!("  {}", horse.walk());      // Delegated to base
+    println!("  {}", horse.walk());      // "Inherited" via blanket Walk impl
     println!("  {}", horse.gallop());    // Extended behavior
-    
+
+    horse.rename("Spirit Jr.");          // "Inherited" mutation via base_mut()
+    println!("  After rename: {}", horse.walk());
+
     // ---------------------------------------------------------------------
     println!("\n6. MULTIPLE TRAITS (Duck can Animal + Swimmer):");
     println!("-".repeat(40));
@@ -286,6 +650,64 @@ fn main() {
     println!("  {}", dog.describe());
     println!("  {}", horse.describe());
     
+    // ---------------------------------------------------------------------
+    println!("\n8. COLLECTIONS DEMO (Zoo behaves like a built-in container):");
+    println!("-".repeat(40));
+
+    let mut zoo = Zoo::new();
+    zoo.add(Box::new(Dog::new("Buddy", 3, "Labrador")));
+    zoo.add(Box::new(Cat::new("Mittens", true)));
+    zoo.add(Box::new(Duck::new("Daffy")));
+
+    println!("  Indexed zoo[0]: {}", zoo[0].describe());
+    println!("  Sliced zoo[0..2]: {} animals", zoo[0..2].len());
+    println!("  Deref .len(): {}", zoo.len());
+
+    for animal in &zoo {
+        println!("  - {}", animal.describe());
+    }
+
+    let collected: Zoo = vec![
+        Box::new(Duck::new("Daisy")) as Box<DynAnimal>,
+        Box::new(Cat::new("Tom", false)) as Box<DynAnimal>,
+    ]
+    .into_iter()
+    .collect();
+    println!("  Collected chorus: {}", collected.chorus());
+
+    // ---------------------------------------------------------------------
+    println!("\n9. FACTORY DEMO (spawn animals by species name):");
+    println!("-".repeat(40));
+
+    let factory = AnimalFactory::with_defaults();
+    if let Some(spawned) = factory.spawn("dog", "Fido") {
+        println!("  Spawned: {}", spawned.describe());
+    }
+    if let Some(spawned) = factory.spawn("duck", "Scrooge") {
+        println!("  Spawned: {}", spawned.describe());
+    }
+    println!("  Unknown species: {:?}", factory.spawn("dragon", "Smaug").is_none());
+
+    // ---------------------------------------------------------------------
+    println!("\n10. MUTABLE STATE DEMO (trait constructor + hunger):");
+    println!("-".repeat(40));
+
+    let mut hungry_duck = <Duck as Animal>::new("Howard");
+    println!("  Freshly hatched: {}", hungry_duck.speak());
+    println!("  Is hungry? {}", hungry_duck.is_hungry());
+    hungry_duck.feed();
+    hungry_duck.feed();
+    println!("  After feeding twice: {} (hunger: {})", hungry_duck.speak(), hungry_duck.hunger());
+
+    // ---------------------------------------------------------------------
+    println!("\n11. ASSOCIATED TYPES DEMO (diet routed through the type system):");
+    println!("-".repeat(40));
+
+    println!("  {:?} prefers {:?}", dog.name(), feed_plan(&dog));
+    println!("  {:?} prefers {:?}", cat.name(), feed_plan(&cat));
+    println!("  {:?} prefers {:?}", duck.name(), feed_plan(&duck));
+    println!("  {:?} prefers {:?}", horse.name(), feed_plan(&horse));
+
     println!("\n{}", "=".repeat(60));
     println!("KEY TAKEAWAYS:");
     println!("{}", "=".repeat(60));